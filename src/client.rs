@@ -0,0 +1,233 @@
+//! A reusable, connection-pooled [`Client`](struct.Client.html) and its
+//! [`ClientBuilder`](struct.ClientBuilder.html).
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use hyper::client::Client as HyperClient;
+use hyper::client::IntoUrl;
+use hyper::header::{Headers, UserAgent};
+use hyper::method::Method;
+
+use {Error, Request};
+use redirect::RedirectPolicy;
+
+/// A reusable HTTP client.
+///
+/// Calling `reru::get`/`reru::post`/etc. directly builds each request
+/// against a shared default client with default settings. Building a
+/// `Client` once lets you configure it (timeouts, default headers,
+/// redirect policy) while hyper still keeps sockets to the same host
+/// alive across the requests made with it. `Client` keeps
+/// its `hyper::Client` behind an `Arc`, so it is cheap to `Clone` and
+/// can be shared across threads.
+#[derive(Clone)]
+pub struct Client {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    hyper_client: HyperClient,
+    // Stored as raw name/value pairs rather than a `hyper::header::Headers`:
+    // `Headers`'s internal caches use an `UnsafeCell` with no `Sync` impl,
+    // which would make `Inner` (and thus the `Arc<Inner>` behind `Client`,
+    // and the `lazy_static!` default client) unable to be shared across
+    // threads. A `Headers` is materialized fresh from these pairs for each
+    // request instead.
+    default_headers: Vec<(String, Vec<Vec<u8>>)>,
+    redirect_policy: RedirectPolicy,
+}
+
+impl fmt::Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("default_headers", &self.inner.default_headers)
+            .finish()
+    }
+}
+
+impl Default for Client {
+    fn default() -> Client {
+        ClientBuilder::new().build()
+    }
+}
+
+impl Client {
+    /// Creates a new `Client` with default settings.
+    pub fn new() -> Client {
+        Client::default()
+    }
+
+    pub(crate) fn hyper_client(&self) -> &HyperClient {
+        &self.inner.hyper_client
+    }
+
+    pub(crate) fn default_headers(&self) -> Headers {
+        let mut headers = Headers::new();
+        for &(ref name, ref values) in &self.inner.default_headers {
+            headers.set_raw(name.clone(), values.clone());
+        }
+        headers
+    }
+
+    pub(crate) fn default_redirect_policy(&self) -> &RedirectPolicy {
+        &self.inner.redirect_policy
+    }
+
+    /// Starts building a GET request bound to this client's connection pool.
+    pub fn get<U: IntoUrl>(&self, url: U) -> Result<Request, Error> {
+        self.request(Method::Get, url)
+    }
+
+    /// Starts building a POST request bound to this client's connection pool.
+    pub fn post<U: IntoUrl>(&self, url: U) -> Result<Request, Error> {
+        self.request(Method::Post, url)
+    }
+
+    /// Starts building a PUT request bound to this client's connection pool.
+    pub fn put<U: IntoUrl>(&self, url: U) -> Result<Request, Error> {
+        self.request(Method::Put, url)
+    }
+
+    /// Starts building a DELETE request bound to this client's connection pool.
+    pub fn delete<U: IntoUrl>(&self, url: U) -> Result<Request, Error> {
+        self.request(Method::Delete, url)
+    }
+
+    /// Starts building a PATCH request bound to this client's connection pool.
+    pub fn patch<U: IntoUrl>(&self, url: U) -> Result<Request, Error> {
+        self.request(Method::Patch, url)
+    }
+
+    fn request<U: IntoUrl>(&self, method: Method, url: U) -> Result<Request, Error> {
+        let mut req = try!(Request::new(method, url));
+        req.bind_client(self.clone());
+        Ok(req)
+    }
+}
+
+/// Builder for configuring a [`Client`](struct.Client.html) once before
+/// using it to send requests.
+pub struct ClientBuilder {
+    default_headers: Headers,
+    read_timeout: Option<Duration>,
+    write_timeout: Option<Duration>,
+    redirect_policy: RedirectPolicy,
+}
+
+impl ClientBuilder {
+    /// Creates a new builder with hyper's defaults.
+    pub fn new() -> ClientBuilder {
+        ClientBuilder {
+            default_headers: Headers::new(),
+            read_timeout: None,
+            write_timeout: None,
+            redirect_policy: RedirectPolicy::default(),
+        }
+    }
+
+    /// Sets the redirect policy requests made with the resulting client
+    /// follow by default. A request can still override this with its own
+    /// [`Request::redirect`](struct.Request.html#method.redirect) call.
+    pub fn redirect_policy(mut self, policy: RedirectPolicy) -> Self {
+        self.redirect_policy = policy;
+        self
+    }
+
+    /// Sets headers to be sent on every request made with the resulting
+    /// client, in addition to any headers set on individual requests.
+    pub fn default_headers(mut self, headers: Headers) -> Self {
+        self.default_headers = headers;
+        self
+    }
+
+    /// Sets the `User-Agent` header sent on every request.
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.default_headers.set(UserAgent(user_agent.to_string()));
+        self
+    }
+
+    /// Sets the socket read timeout used by the underlying `hyper::Client`.
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the socket write timeout used by the underlying `hyper::Client`.
+    pub fn write_timeout(mut self, timeout: Duration) -> Self {
+        self.write_timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the `Client`.
+    pub fn build(self) -> Client {
+        let mut hyper_client = HyperClient::new();
+        hyper_client.set_read_timeout(self.read_timeout);
+        hyper_client.set_write_timeout(self.write_timeout);
+        // Redirects are followed by `Request::request_with_client` under
+        // reru's own `RedirectPolicy`, so hyper must hand every 3xx back.
+        hyper_client.set_redirect_policy(::hyper::client::RedirectPolicy::FollowNone);
+
+        let default_headers = self.default_headers
+            .iter()
+            .map(|view| {
+                let raw = self.default_headers.get_raw(view.name()).unwrap_or(&[]);
+                (view.name().to_string(), raw.to_vec())
+            })
+            .collect();
+
+        Client {
+            inner: Arc::new(Inner {
+                hyper_client: hyper_client,
+                default_headers: default_headers,
+                redirect_policy: self.redirect_policy,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hyper::header::Headers;
+
+    #[test]
+    fn user_agent_is_carried_into_default_headers() {
+        let client = ClientBuilder::new().user_agent("reru-tests/1.0").build();
+        let headers = client.default_headers();
+        assert_eq!(headers.get::<UserAgent>().map(|ua| ua.0.as_str()),
+                   Some("reru-tests/1.0"));
+    }
+
+    #[test]
+    fn default_headers_are_materialized_on_every_call() {
+        let mut headers = Headers::new();
+        headers.set(UserAgent("custom-agent".to_string()));
+        let client = ClientBuilder::new().default_headers(headers).build();
+
+        assert_eq!(client.default_headers().get::<UserAgent>().map(|ua| ua.0.as_str()),
+                   Some("custom-agent"));
+        // Calling it again must still work: the headers aren't consumed.
+        assert_eq!(client.default_headers().get::<UserAgent>().map(|ua| ua.0.as_str()),
+                   Some("custom-agent"));
+    }
+
+    #[test]
+    fn redirect_policy_defaults_to_limit_ten() {
+        let client = Client::new();
+        match *client.default_redirect_policy() {
+            RedirectPolicy::Limit(10) => {}
+            ref other => panic!("expected RedirectPolicy::Limit(10), got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn redirect_policy_can_be_overridden() {
+        let client = ClientBuilder::new().redirect_policy(RedirectPolicy::None).build();
+        match *client.default_redirect_policy() {
+            RedirectPolicy::None => {}
+            ref other => panic!("expected RedirectPolicy::None, got {:?}", other),
+        }
+    }
+}
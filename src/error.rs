@@ -0,0 +1,141 @@
+//! A crate-level [`Error`](enum.Error.html) unifying reru's various
+//! failure modes.
+
+use std::fmt;
+use std::io;
+use std::error::Error as StdError;
+
+use url;
+use hyper::Error as HyperError;
+use hyper::status::StatusCode;
+use url::Url;
+
+#[cfg(feature = "json")]
+use serde_json::error::Error as JsonError;
+#[cfg(feature = "json")]
+use serde_urlencoded;
+
+/// A unified error type covering URL parsing, HTTP transport, and
+/// (de)serialization failures, so callers can match on one type
+/// instead of the several distinct error types the rest of the API
+/// surfaces, similar to how the fatcat/google-apis generated clients
+/// branch on a single error enum.
+#[derive(Debug)]
+pub enum Error {
+    /// A URL failed to parse.
+    UrlParse(url::ParseError),
+    /// The underlying HTTP transport (hyper) failed.
+    Http(HyperError),
+    /// The response's status was 4xx or 5xx.
+    Status(StatusCode, Url),
+    /// An I/O failure reading a response body, or a redirect that could
+    /// not be followed (too many redirects, or a streamed request body
+    /// that cannot be sent again).
+    Io(io::Error),
+    /// A `RestPath::get_path` implementation failed for its parameter.
+    #[cfg(feature = "json")]
+    Path(String),
+    /// A JSON (de)serialization failure.
+    #[cfg(feature = "json")]
+    Json(JsonError),
+    /// A `application/x-www-form-urlencoded` decoding failure.
+    #[cfg(feature = "json")]
+    FormDecode(serde_urlencoded::de::Error),
+    /// A `application/x-www-form-urlencoded` encoding failure.
+    #[cfg(feature = "json")]
+    FormEncode(serde_urlencoded::ser::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::UrlParse(ref e) => write!(f, "URL parse error: {}", e),
+            Error::Http(ref e) => write!(f, "HTTP error: {}", e),
+            Error::Status(status, ref url) => write!(f, "HTTP status error ({}) for {}", status, url),
+            Error::Io(ref e) => write!(f, "I/O error: {}", e),
+            #[cfg(feature = "json")]
+            Error::Path(ref msg) => write!(f, "REST path error: {}", msg),
+            #[cfg(feature = "json")]
+            Error::Json(ref e) => write!(f, "JSON error: {}", e),
+            #[cfg(feature = "json")]
+            Error::FormDecode(ref e) => write!(f, "form decode error: {}", e),
+            #[cfg(feature = "json")]
+            Error::FormEncode(ref e) => write!(f, "form encode error: {}", e),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::UrlParse(ref e) => e.description(),
+            Error::Http(ref e) => e.description(),
+            Error::Status(..) => "HTTP status error",
+            Error::Io(ref e) => e.description(),
+            #[cfg(feature = "json")]
+            Error::Path(ref msg) => msg,
+            #[cfg(feature = "json")]
+            Error::Json(ref e) => e.description(),
+            #[cfg(feature = "json")]
+            Error::FormDecode(ref e) => e.description(),
+            #[cfg(feature = "json")]
+            Error::FormEncode(ref e) => e.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            Error::UrlParse(ref e) => Some(e),
+            Error::Http(ref e) => Some(e),
+            Error::Status(..) => None,
+            Error::Io(ref e) => Some(e),
+            #[cfg(feature = "json")]
+            Error::Path(_) => None,
+            #[cfg(feature = "json")]
+            Error::Json(ref e) => Some(e),
+            #[cfg(feature = "json")]
+            Error::FormDecode(ref e) => Some(e),
+            #[cfg(feature = "json")]
+            Error::FormEncode(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<url::ParseError> for Error {
+    fn from(e: url::ParseError) -> Error {
+        Error::UrlParse(e)
+    }
+}
+
+impl From<HyperError> for Error {
+    fn from(e: HyperError) -> Error {
+        Error::Http(e)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        Error::Io(e)
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<JsonError> for Error {
+    fn from(e: JsonError) -> Error {
+        Error::Json(e)
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<serde_urlencoded::ser::Error> for Error {
+    fn from(e: serde_urlencoded::ser::Error) -> Error {
+        Error::FormEncode(e)
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<serde_urlencoded::de::Error> for Error {
+    fn from(e: serde_urlencoded::de::Error) -> Error {
+        Error::FormDecode(e)
+    }
+}
@@ -0,0 +1,38 @@
+//! Controlling whether and how a request follows HTTP redirects.
+
+use std::fmt;
+use std::sync::Arc;
+
+use url::Url;
+
+/// Controls how many, if any, redirects a request will follow.
+///
+/// The default, used when neither the request nor its `Client` set one
+/// explicitly, is `RedirectPolicy::Limit(10)`.
+#[derive(Clone)]
+pub enum RedirectPolicy {
+    /// Follow up to the given number of redirects, then give up with an
+    /// error.
+    Limit(u32),
+    /// Never follow redirects; hand the 3xx response back as-is.
+    None,
+    /// Call the given function with each redirect's target URL to
+    /// decide whether to follow it.
+    Custom(Arc<Fn(&Url) -> bool + Send + Sync>),
+}
+
+impl Default for RedirectPolicy {
+    fn default() -> RedirectPolicy {
+        RedirectPolicy::Limit(10)
+    }
+}
+
+impl fmt::Debug for RedirectPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RedirectPolicy::Limit(n) => write!(f, "RedirectPolicy::Limit({})", n),
+            RedirectPolicy::None => write!(f, "RedirectPolicy::None"),
+            RedirectPolicy::Custom(_) => write!(f, "RedirectPolicy::Custom(..)"),
+        }
+    }
+}
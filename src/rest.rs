@@ -0,0 +1,141 @@
+//! A typed REST resource client built on top of [`Client`](../struct.Client.html),
+//! in the style of the `restson` crate.
+
+use url;
+use url::Url;
+use hyper::client::IntoUrl;
+use serde::ser::Serialize;
+use serde::de::Deserialize;
+
+use {Client, Error, Response};
+
+/// Maps a parameter to the path of a REST resource, relative to a
+/// [`RestClient`](struct.RestClient.html)'s base URL.
+///
+/// Implement this for your data type so it can be used with
+/// `RestClient`'s typed `get`/`post`/`put`/`patch` methods instead of
+/// building URLs and (de)serializing bodies by hand.
+///
+/// ```rust,ignore
+/// struct Widget { id: u32, name: String }
+///
+/// impl RestPath<u32> for Widget {
+///     fn get_path(id: u32) -> Result<String, Error> {
+///         Ok(format!("widgets/{}", id))
+///     }
+/// }
+/// ```
+pub trait RestPath<P> {
+    /// Returns the path (relative to the `RestClient`'s base URL) for `param`.
+    fn get_path(param: P) -> Result<String, Error>;
+}
+
+/// A typed client for a REST API, combining a [`Client`](../struct.Client.html)
+/// with a base URL that resource paths are resolved against.
+#[derive(Clone, Debug)]
+pub struct RestClient {
+    client: Client,
+    base_url: Url,
+}
+
+impl RestClient {
+    /// Creates a `RestClient` sending requests through `client`, with
+    /// resource paths resolved against `base_url`.
+    ///
+    /// `base_url` is given a trailing `/` if it doesn't already have
+    /// one, so a base path (e.g. `https://api.example.com/v1`) is kept
+    /// as a prefix when resolving paths rather than being partially
+    /// overwritten by `Url::join`'s RFC 3986 relative-reference
+    /// resolution (which treats the last path segment the way a
+    /// filename is treated by a relative link).
+    pub fn new<U: IntoUrl>(client: Client, base_url: U) -> Result<RestClient, url::ParseError> {
+        let mut base_url = try!(base_url.into_url());
+        if !base_url.path().ends_with('/') {
+            let path = format!("{}/", base_url.path());
+            base_url.set_path(&path);
+        }
+
+        Ok(RestClient {
+            client: client,
+            base_url: base_url,
+        })
+    }
+
+    fn resolve<T, P>(&self, param: P) -> Result<Url, Error>
+        where T: RestPath<P>
+    {
+        let path = try!(T::get_path(param));
+        Ok(try!(self.base_url.join(&path)))
+    }
+
+    /// Fetches the resource identified by `param` and deserializes it
+    /// from JSON.
+    pub fn get<T, P>(&self, param: P) -> Result<T, Error>
+        where T: RestPath<P> + Deserialize
+    {
+        let url = try!(self.resolve::<T, P>(param));
+        let req = try!(self.client.get(url));
+        let res = try!(req.request());
+        Ok(try!(res.parse_json()))
+    }
+
+    /// Serializes `data` as JSON and `POST`s it to its resource path.
+    pub fn post<T>(&self, data: &T) -> Result<Response, Error>
+        where T: RestPath<()> + Serialize
+    {
+        let url = try!(self.resolve::<T, ()>(()));
+        let req = try!(try!(self.client.post(url)).body_json(data));
+        Ok(try!(req.request()))
+    }
+
+    /// Serializes `data` as JSON and `PUT`s it to its resource path.
+    pub fn put<T>(&self, data: &T) -> Result<Response, Error>
+        where T: RestPath<()> + Serialize
+    {
+        let url = try!(self.resolve::<T, ()>(()));
+        let req = try!(try!(self.client.put(url)).body_json(data));
+        Ok(try!(req.request()))
+    }
+
+    /// Serializes `data` as JSON and `PATCH`es it to its resource path.
+    pub fn patch<T>(&self, data: &T) -> Result<Response, Error>
+        where T: RestPath<()> + Serialize
+    {
+        let url = try!(self.resolve::<T, ()>(()));
+        let req = try!(try!(self.client.patch(url)).body_json(data));
+        Ok(try!(req.request()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Client;
+
+    struct Widget;
+
+    impl RestPath<u32> for Widget {
+        fn get_path(id: u32) -> Result<String, Error> {
+            Ok(format!("widgets/{}", id))
+        }
+    }
+
+    #[test]
+    fn new_adds_a_trailing_slash_to_the_base_path() {
+        let client = RestClient::new(Client::new(), "https://api.example.com/v1").unwrap();
+        assert_eq!(client.base_url.as_str(), "https://api.example.com/v1/");
+    }
+
+    #[test]
+    fn new_leaves_an_existing_trailing_slash_alone() {
+        let client = RestClient::new(Client::new(), "https://api.example.com/v1/").unwrap();
+        assert_eq!(client.base_url.as_str(), "https://api.example.com/v1/");
+    }
+
+    #[test]
+    fn resolve_keeps_the_base_path_as_a_prefix() {
+        let client = RestClient::new(Client::new(), "https://api.example.com/v1").unwrap();
+        let url = client.resolve::<Widget, u32>(5).unwrap();
+        assert_eq!(url.as_str(), "https://api.example.com/v1/widgets/5");
+    }
+}